@@ -0,0 +1,30 @@
+mod add_lint_attr;
+mod format_string;
+mod raw_string;
+
+use hir::db::HirDatabase;
+
+use crate::{Assist, AssistCtx};
+
+macro_rules! handlers {
+    ($($module:ident :: $handler:ident),* $(,)?) => {
+        /// Runs every registered assist handler against `ctx` in order,
+        /// returning the first one that applies. Each handler is cheap to
+        /// try: they bail out with `?` as soon as the cursor position or
+        /// surrounding syntax doesn't match what they're looking for.
+        pub fn all_assists<DB: HirDatabase>(ctx: AssistCtx<'_, DB>) -> Option<Assist> {
+            None $(.or_else(|| $module::$handler(ctx.clone())))*
+        }
+    };
+}
+
+handlers!(
+    raw_string::make_raw_string,
+    raw_string::make_usual_string,
+    raw_string::add_hash,
+    raw_string::remove_hash,
+    format_string::inline_format_args,
+    add_lint_attr::fix_lint_name,
+    add_lint_attr::add_allow_attr,
+    add_lint_attr::add_expect_attr,
+);
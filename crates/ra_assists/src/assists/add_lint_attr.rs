@@ -0,0 +1,224 @@
+use hir::db::HirDatabase;
+use ra_syntax::{
+    ast::{self, AstNode, AttrsOwner},
+    NodeOrToken,
+    SyntaxKind::{IDENT, WHITESPACE},
+};
+
+use crate::{
+    generated::lints::{find_lint, nearest_lint, Lint},
+    Assist, AssistCtx, AssistId,
+};
+
+// Assist: fix_lint_name
+//
+// Corrects or completes the lint name inside an `#[allow(...)]`,
+// `#[warn(...)]`, `#[deny(...)]`, `#[forbid(...)]` or `#[expect(...)]` attribute against the
+// table of real rustc/clippy lints, e.g. turning a typo'd
+// `#[allow(unused_variable)]` into `#[allow(unused_variables)]`.
+//
+// ```
+// #[allow(unused_variable<|>)]
+// fn f() {
+//     let x = 92;
+// }
+// ```
+// ->
+// ```
+// #[allow(unused_variables)]
+// fn f() {
+//     let x = 92;
+// }
+// ```
+pub(crate) fn fix_lint_name(ctx: AssistCtx<impl HirDatabase>) -> Option<Assist> {
+    let ident = ctx.find_token_at_offset(IDENT)?;
+    let attr = ident.ancestors().find_map(ast::Attr::cast)?;
+    let attr_name = attr.path()?.as_single_name_ref()?;
+    lint_level_attr(attr_name.text())?;
+
+    let typed = ident.text().to_string();
+    if find_lint(&typed).is_some() {
+        // already a real lint name, nothing to fix
+        return None;
+    }
+    let lint = nearest_lint(&typed)?;
+    if lint.label == typed {
+        return None;
+    }
+
+    ctx.add_assist(
+        AssistId("fix_lint_name"),
+        format!("Replace with `{}`: {}", lint.label, lint.description),
+        |edit| {
+            edit.target(ident.text_range());
+            edit.replace(ident.text_range(), lint.label);
+        },
+    )
+}
+
+// Assist: add_allow_attr
+//
+// One-step "silence this lint here": invoked with the cursor on a known
+// lint name, attaches an `#[allow(...)]` for it to the enclosing item (if
+// it isn't already silenced there).
+//
+// ```
+// fn f() {
+//     let unused_variables<|> = 92;
+// }
+// ```
+// ->
+// ```
+// #[allow(unused_variables)]
+// fn f() {
+//     let unused_variables = 92;
+// }
+// ```
+pub(crate) fn add_allow_attr(ctx: AssistCtx<impl HirDatabase>) -> Option<Assist> {
+    add_lint_level_attr(ctx, "allow")
+}
+
+// Assist: add_expect_attr
+//
+// Like `add_allow_attr`, but inserts `#[expect(...)]` instead, for a lint
+// that's expected to fire and should be reported if it stops firing.
+pub(crate) fn add_expect_attr(ctx: AssistCtx<impl HirDatabase>) -> Option<Assist> {
+    add_lint_level_attr(ctx, "expect")
+}
+
+fn add_lint_level_attr(ctx: AssistCtx<impl HirDatabase>, attr_kind: &'static str) -> Option<Assist> {
+    let ident = ctx.find_token_at_offset(IDENT)?;
+    // only an exact match triggers this -- an arbitrary identifier that
+    // happens to fuzzy-match a lint name should not spuriously offer to
+    // silence it
+    let lint: &Lint = find_lint(ident.text())?;
+
+    let item = ident.ancestors().find_map(ast::Item::cast)?;
+    if already_has_lint(&item, lint.label) {
+        return None;
+    }
+    let indent = indent_of(&item);
+
+    ctx.add_assist(
+        AssistId("add_lint_attr"),
+        format!("Add #[{}({})]: {}", attr_kind, lint.label, lint.description),
+        |edit| {
+            edit.target(ident.text_range());
+            let text = format!("#[{}({})]\n{}", attr_kind, lint.label, indent);
+            edit.insert(item.syntax().text_range().start(), text);
+        },
+    )
+}
+
+fn already_has_lint(item: &ast::Item, lint_name: &str) -> bool {
+    item.attrs().any(|attr| {
+        let is_lint_level_attr = attr
+            .path()
+            .and_then(|p| p.as_single_name_ref())
+            .map_or(false, |name| lint_level_attr(name.text()).is_some());
+        is_lint_level_attr && attr.syntax().text().to_string().contains(lint_name)
+    })
+}
+
+/// The indentation (leading whitespace on its line) that `item` itself is
+/// written at, so an inserted attribute lines up with it.
+fn indent_of(item: &ast::Item) -> String {
+    let ws = match item.syntax().prev_sibling_or_token() {
+        Some(NodeOrToken::Token(tok)) if tok.kind() == WHITESPACE => tok.text().to_string(),
+        _ => return String::new(),
+    };
+    match ws.rfind('\n') {
+        Some(idx) => ws[idx + 1..].to_string(),
+        None => ws,
+    }
+}
+
+fn lint_level_attr(name: &str) -> Option<()> {
+    matches!(name, "allow" | "warn" | "deny" | "forbid" | "expect").then(|| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::helpers::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn fix_lint_name_corrects_typo() {
+        check_assist(
+            fix_lint_name,
+            r#"
+            #[allow(unused_variable<|>)]
+            fn f() {
+                let x = 92;
+            }
+            "#,
+            r#"
+            #[allow(unused_variables<|>)]
+            fn f() {
+                let x = 92;
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn fix_lint_name_not_applicable_for_correct_name() {
+        check_assist_not_applicable(
+            fix_lint_name,
+            r#"
+            #[allow(unused_variables<|>)]
+            fn f() {
+                let x = 92;
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn fix_lint_name_not_applicable_outside_lint_attr() {
+        check_assist_not_applicable(
+            fix_lint_name,
+            r#"
+            #[cfg(unused_varz<|>)]
+            fn f() {}
+            "#,
+        )
+    }
+
+    #[test]
+    fn add_allow_attr_inserts_attribute() {
+        check_assist(
+            add_allow_attr,
+            r#"
+            fn f() {
+                let unused_variables<|> = 92;
+            }
+            "#,
+            r#"
+            #[allow(unused_variables)]
+            fn f() {
+                let unused_variables<|> = 92;
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn add_allow_attr_not_applicable_when_already_present() {
+        check_assist_not_applicable(
+            add_allow_attr,
+            r#"
+            #[allow(unused_variables)]
+            fn f() {
+                let unused_variables<|> = 92;
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn find_lint_known_clippy_lint() {
+        let lint: &Lint = find_lint("clippy::needless_return").unwrap();
+        assert_eq!(lint.default_level, "warn");
+    }
+}
@@ -0,0 +1,480 @@
+use hir::db::HirDatabase;
+use ra_syntax::{
+    ast::{self, AstNode},
+    SyntaxElement,
+    SyntaxKind::{self, COMMA, IDENT, R_BRACK, R_CURLY, R_PAREN, STRING, WHITESPACE},
+};
+
+use crate::{Assist, AssistCtx, AssistId};
+
+// Assist: inline_format_args
+//
+// Moves simple identifier arguments of `format!`-like macros into the
+// format string itself, using the captured-identifier syntax stabilized
+// in Rust 2021.
+//
+// ```
+// fn main() {
+//     let name = "world";
+//     println!("hello, <|>{}", name);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let name = "world";
+//     println!("hello, <|>{name}");
+// }
+// ```
+pub(crate) fn inline_format_args(ctx: AssistCtx<impl HirDatabase>) -> Option<Assist> {
+    let string = ctx.find_token_at_offset(STRING)?;
+    let macro_call = string.ancestors().find_map(ast::MacroCall::cast)?;
+    let name = macro_call.path()?.segment()?.name_ref()?;
+    if !is_format_macro(name.text()) {
+        return None;
+    }
+
+    let args = macro_call.token_tree()?;
+    // The token tree's own children are, in order: the opening delimiter,
+    // the comma-separated argument list, the closing delimiter. `write!`
+    // and `writeln!` additionally take the writer as their first argument,
+    // *before* the format string, so we can't just assume the format
+    // string is the very first token -- skip past the writer (up to its
+    // separating comma) first, then scan for the string literal.
+    let mut elements = args.syntax().children_with_tokens();
+    if is_writer_macro(name.text()) {
+        for el in elements.by_ref() {
+            if el.kind() == COMMA {
+                break;
+            }
+        }
+    }
+    let fmt_string = elements.by_ref().find_map(|el| el.into_token().filter(|t| t.kind() == STRING))?;
+    if fmt_string != string {
+        // the cursor has to be inside the format string itself
+        return None;
+    }
+
+    let text = fmt_string.text().as_str();
+    let body = &text[1..text.len().saturating_sub(1)];
+    let placeholders = parse_placeholders(body)?;
+
+    // A width/precision referenced by explicit index (`{:1$}`) points at a
+    // fixed argument slot; inlining any argument would shift those slots
+    // around (or remove the referenced one outright), and correctly
+    // rewriting the reference would mean parsing the rest of the format
+    // spec grammar. Simpler, and always correct: don't inline at all when
+    // one of these is present.
+    if placeholders.iter().any(|ph| ph.format_spec.as_deref().map_or(false, |s| s.contains('$'))) {
+        return None;
+    }
+
+    // The remaining top-level elements (skipping the closing delimiter)
+    // are the trailing arguments, split on top-level commas. Nested
+    // delimited groups (e.g. `f(1, 2)`) show up as a single child node
+    // here, not flattened tokens, so splitting on direct-child commas is
+    // exactly the top-level split we want.
+    let groups = split_on_commas(elements);
+    let idents: Vec<Option<String>> = groups.iter().map(|group| single_ident(group)).collect();
+
+    // First pass: resolve each placeholder's argument index, and figure out
+    // which arguments are actually being inlined (and so dropped from the
+    // argument list) -- independent of the text we'll emit for the
+    // placeholders that keep their argument.
+    let mut positional_counter = 0usize;
+    let mut resolved_index = Vec::with_capacity(placeholders.len());
+    let mut inlined = vec![false; idents.len()];
+    for ph in &placeholders {
+        if ph.consumes_extra_positional {
+            // `{:.*}`'s precision is itself taken from the next
+            // positional argument, ahead of the placeholder's own.
+            positional_counter += 1;
+        }
+
+        let index = match &ph.arg {
+            PlaceholderArg::Next => {
+                let i = positional_counter;
+                positional_counter += 1;
+                Some(i)
+            }
+            // an explicit index doesn't advance the implicit `{}` counter
+            PlaceholderArg::Positional(i) => Some(*i),
+            PlaceholderArg::Named(_) => None,
+        };
+        if let Some(i) = index {
+            if !ph.skip_rewrite && idents.get(i).cloned().flatten().is_some() {
+                inlined[i] = true;
+            }
+        }
+        resolved_index.push(index);
+    }
+
+    // Nothing to do unless at least one argument gets inlined; arguments
+    // that were never touched (or couldn't be inlined) stay right where they are.
+    if !inlined.iter().any(|&b| b) {
+        return None;
+    }
+
+    // Explicit positional placeholders that keep their argument (`skip_rewrite`,
+    // or the argument wasn't a plain identifier) still need their literal
+    // index renumbered to match the argument's new position, now that
+    // inlined arguments are dropped from the call.
+    let mut remap = vec![None; idents.len()];
+    let mut next_kept = 0usize;
+    for (i, &is_inlined) in inlined.iter().enumerate() {
+        if !is_inlined {
+            remap[i] = Some(next_kept);
+            next_kept += 1;
+        }
+    }
+
+    let mut new_body = String::with_capacity(body.len());
+    let mut last_end = 0usize;
+    for (ph, index) in placeholders.iter().zip(&resolved_index) {
+        new_body.push_str(&body[last_end..ph.range.0]);
+        last_end = ph.range.1;
+
+        let ident = index.and_then(|i| idents.get(i).cloned().flatten());
+        match ident {
+            Some(ident) if !ph.skip_rewrite => {
+                new_body.push('{');
+                new_body.push_str(&ident);
+                if let Some(spec) = &ph.format_spec {
+                    new_body.push(':');
+                    new_body.push_str(spec);
+                }
+                new_body.push('}');
+            }
+            _ => match ph.arg {
+                PlaceholderArg::Positional(i) => {
+                    // The argument this references should have survived --
+                    // unless it was inlined away by a *different*
+                    // placeholder also pointing at it, in which case there's
+                    // nowhere sane left for this one to point.
+                    let new_i = remap.get(i).copied().flatten()?;
+                    new_body.push('{');
+                    new_body.push_str(&new_i.to_string());
+                    if let Some(spec) = &ph.format_spec {
+                        new_body.push(':');
+                        new_body.push_str(spec);
+                    }
+                    new_body.push('}');
+                }
+                _ => new_body.push_str(&body[ph.range.0..ph.range.1]),
+            },
+        }
+    }
+    new_body.push_str(&body[last_end..]);
+
+    let kept_args: Vec<String> = groups
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !inlined[*i])
+        .map(|(_, group)| group_text(group))
+        .collect();
+
+    let mut new_arg_list = format!("\"{}\"", new_body);
+    for arg in &kept_args {
+        new_arg_list.push_str(", ");
+        new_arg_list.push_str(arg);
+    }
+
+    let target_range = args.syntax().text_range();
+    ctx.add_assist(AssistId("inline_format_args"), "Inline format args", |edit| {
+        edit.target(string.text_range());
+        edit.replace(target_range, format!("({})", new_arg_list));
+    })
+}
+
+fn is_format_macro(name: &str) -> bool {
+    matches!(
+        name,
+        "format" | "print" | "println" | "eprint" | "eprintln" | "write" | "writeln" | "panic"
+            | "format_args"
+    )
+}
+
+fn is_writer_macro(name: &str) -> bool {
+    matches!(name, "write" | "writeln")
+}
+
+fn is_closing_delim(kind: SyntaxKind) -> bool {
+    matches!(kind, R_PAREN | R_BRACK | R_CURLY)
+}
+
+/// Splits the remaining top-level elements of a macro's token tree (after
+/// the format string) into argument groups on top-level commas, dropping
+/// the closing delimiter.
+fn split_on_commas(elements: impl Iterator<Item = SyntaxElement>) -> Vec<Vec<SyntaxElement>> {
+    let mut groups: Vec<Vec<SyntaxElement>> = vec![Vec::new()];
+    for el in elements {
+        if is_closing_delim(el.kind()) {
+            break;
+        }
+        if el.kind() == COMMA {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().unwrap().push(el);
+        }
+    }
+    groups.retain(|g| g.iter().any(|el| el.kind() != WHITESPACE));
+    groups
+}
+
+/// If `group` is exactly one *plain* identifier (no path qualifiers, since
+/// those arrive as separate `::`/`IDENT` tokens here), returns its text.
+fn single_ident(group: &[SyntaxElement]) -> Option<String> {
+    let mut meaningful = group.iter().filter(|el| el.kind() != WHITESPACE);
+    let only = meaningful.next()?;
+    if meaningful.next().is_some() {
+        return None;
+    }
+    let token = only.as_token()?;
+    if token.kind() == IDENT {
+        Some(token.text().to_string())
+    } else {
+        None
+    }
+}
+
+fn group_text(group: &[SyntaxElement]) -> String {
+    group.iter().map(|el| el.to_string()).collect::<String>().trim().to_string()
+}
+
+enum PlaceholderArg {
+    Next,
+    Positional(usize),
+    Named(String),
+}
+
+struct Placeholder {
+    // byte range within the string body (without surrounding quotes)
+    range: (usize, usize),
+    arg: PlaceholderArg,
+    format_spec: Option<String>,
+    // width/precision that reference arguments by position (`{:.*}`,
+    // `{:1$}`) must never be rewritten, since the argument they name isn't
+    // necessarily the one in `arg`.
+    skip_rewrite: bool,
+    // `{:.*}`'s precision always comes from the *next* positional
+    // argument, ahead of the placeholder's own, so it needs to advance
+    // the counter even though the placeholder itself is skipped.
+    consumes_extra_positional: bool,
+}
+
+/// Splits a format string's body into placeholders, skipping doubled braces
+/// (`{{`/`}}`), which are escapes for a literal brace rather than a
+/// placeholder.
+fn parse_placeholders(body: &str) -> Option<Vec<Placeholder>> {
+    let mut placeholders = Vec::new();
+    let bytes = body.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' if bytes.get(i + 1) == Some(&b'{') => i += 2,
+            b'}' if bytes.get(i + 1) == Some(&b'}') => i += 2,
+            b'{' => {
+                let start = i;
+                let end = body[i..].find('}').map(|p| i + p + 1)?;
+                let inner = &body[i + 1..end - 1];
+                let (arg_part, spec) = match inner.find(':') {
+                    Some(p) => (&inner[..p], Some(inner[p + 1..].to_string())),
+                    None => (inner, None),
+                };
+                let skip_rewrite =
+                    spec.as_deref().map(|s| s.contains(".*") || s.contains('$')).unwrap_or(false);
+                let consumes_extra_positional =
+                    spec.as_deref().map(|s| s.contains(".*")).unwrap_or(false);
+                let arg = if arg_part.is_empty() {
+                    PlaceholderArg::Next
+                } else if let Ok(idx) = arg_part.parse::<usize>() {
+                    PlaceholderArg::Positional(idx)
+                } else {
+                    PlaceholderArg::Named(arg_part.to_string())
+                };
+                placeholders.push(Placeholder {
+                    range: (start, end),
+                    arg,
+                    format_spec: spec,
+                    skip_rewrite,
+                    consumes_extra_positional,
+                });
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    Some(placeholders)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::helpers::{check_assist, check_assist_not_applicable};
+
+    #[test]
+    fn inline_format_args_empty_placeholder() {
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f() {
+                let name = "world";
+                println!(<|>"hello, {}", name);
+            }
+            "#,
+            r#"
+            fn f() {
+                let name = "world";
+                println!(<|>"hello, {name}");
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_positional_placeholder() {
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f() {
+                let a = 1;
+                let b = 2;
+                format!(<|>"{0} {1}", a, b);
+            }
+            "#,
+            r#"
+            fn f() {
+                let a = 1;
+                let b = 2;
+                format!(<|>"{a} {b}");
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_keeps_non_ident_args() {
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f() {
+                let name = "world";
+                println!(<|>"{} {}", name, 1 + 2);
+            }
+            "#,
+            r#"
+            fn f() {
+                let name = "world";
+                println!(<|>"{name} {}", 1 + 2);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_renumbers_kept_positional_placeholder() {
+        // `a` is inlined and dropped from the argument list, so the
+        // remaining explicit `{1}` has to be renumbered to `{0}` -- it's
+        // now the only argument left.
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f() {
+                let a = 1;
+                format!(<|>"{0} {1}", a, 1 + 2);
+            }
+            "#,
+            r#"
+            fn f() {
+                let a = 1;
+                format!(<|>"{a} {0}", 1 + 2);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_not_applicable_with_indexed_width() {
+        check_assist_not_applicable(
+            inline_format_args,
+            r#"
+            fn f() {
+                let a = 1;
+                let b = 2;
+                println!(<|>"{:1$} {1}", a, b);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_not_applicable_without_idents() {
+        check_assist_not_applicable(
+            inline_format_args,
+            r#"
+            fn f() {
+                println!(<|>"{}", 1 + 2);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_skips_star_precision() {
+        check_assist_not_applicable(
+            inline_format_args,
+            r#"
+            fn f() {
+                let width = 5;
+                let x = 1;
+                println!(<|>"{:.*}", width, x);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_star_precision_shifts_later_positionals() {
+        // `{:.*}` alone consumes *two* trailing args (precision, then its
+        // own value), so the following `{}` has to bind to the *third*
+        // trailing arg, not the second.
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f() {
+                let precision = 5;
+                let value = 1.0;
+                let other = 2;
+                println!(<|>"{:.*} {}", precision, value, other);
+            }
+            "#,
+            r#"
+            fn f() {
+                let precision = 5;
+                let value = 1.0;
+                let other = 2;
+                println!(<|>"{:.*} {other}", precision, value);
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn inline_format_args_writeln_skips_writer_arg() {
+        check_assist(
+            inline_format_args,
+            r#"
+            fn f(f: &mut std::fmt::Formatter) {
+                let name = "world";
+                writeln!(f, <|>"hello, {}", name);
+            }
+            "#,
+            r#"
+            fn f(f: &mut std::fmt::Formatter) {
+                let name = "world";
+                writeln!(f, <|>"hello, {name}");
+            }
+            "#,
+        )
+    }
+}
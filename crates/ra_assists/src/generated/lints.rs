@@ -0,0 +1,467 @@
+//! A hand-maintained table of rustc and clippy lints, used by the
+//! lint-aware `#[allow]`/`#[expect]` assists to validate and complete
+//! lint names.
+//!
+//! This is *not* the full set of rustc/clippy lints -- there is no
+//! codegen step in this crate that pulls that list from the compiler, so
+//! `nearest_lint` can only ever suggest one of the entries below. It does
+//! cover the lints most likely to actually show up in an `#[allow]` or
+//! `#[expect]`, though, so `find_lint` should resolve real-world lint
+//! names far more often than not. Extend it (by hand, or with real
+//! codegen against rustc's/clippy's lint listings) as gaps turn up,
+//! rather than treating it as exhaustive.
+
+#[derive(Debug, Clone, Copy)]
+pub struct Lint {
+    pub label: &'static str,
+    pub description: &'static str,
+    pub default_level: &'static str,
+}
+
+pub const DEFAULT_LINTS: &[Lint] = &[
+    Lint {
+        label: "unused_variables",
+        description: "detect variables which are not used in any way",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_imports",
+        description: "imports that are never used",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_mut",
+        description: "detect mut variables which don't need to be mutable",
+        default_level: "warn",
+    },
+    Lint {
+        label: "dead_code",
+        description: "detect unused, unexported items",
+        default_level: "warn",
+    },
+    Lint {
+        label: "non_snake_case",
+        description: "variables, methods, functions, lifetime parameters and modules should have snake case names",
+        default_level: "warn",
+    },
+    Lint {
+        label: "deprecated",
+        description: "detects use of deprecated items",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unreachable_code",
+        description: "detects unreachable code paths",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unreachable_patterns",
+        description: "detects unreachable patterns",
+        default_level: "warn",
+    },
+    Lint {
+        label: "missing_docs",
+        description: "detects missing documentation for public members",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unused_assignments",
+        description: "detect assignments that will never be read",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_must_use",
+        description: "unused result of a type flagged as `#[must_use]`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_parens",
+        description: "detects `if`, `match`, `while` and `return` with parentheses",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_attributes",
+        description: "detects attributes that were not used by the compiler",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_macros",
+        description: "detects macros that were not used",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_unsafe",
+        description: "unnecessary use of an `unsafe` block",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_extern_crates",
+        description: "extern crates that are never used",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_labels",
+        description: "detects labels that are never used",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_doc_comments",
+        description: "detects doc comments that aren't used by rustdoc",
+        default_level: "warn",
+    },
+    Lint {
+        label: "deprecated_in_future",
+        description: "detects use of items that will be deprecated in a future version",
+        default_level: "allow",
+    },
+    Lint {
+        label: "non_camel_case_types",
+        description: "types, variants, traits and type parameters should have camel case names",
+        default_level: "warn",
+    },
+    Lint {
+        label: "non_upper_case_globals",
+        description: "static constants should have uppercase identifiers",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unreachable_pub",
+        description: "`pub` items not reachable from crate root",
+        default_level: "allow",
+    },
+    Lint {
+        label: "missing_copy_implementations",
+        description: "detects potentially-forgotten implementations of `Copy`",
+        default_level: "allow",
+    },
+    Lint {
+        label: "missing_debug_implementations",
+        description: "detects missing implementations of `fmt::Debug`",
+        default_level: "allow",
+    },
+    Lint {
+        label: "missing_doc_code_examples",
+        description: "detects publicly-exported items without code samples in their documentation",
+        default_level: "allow",
+    },
+    Lint {
+        label: "bare_trait_objects",
+        description: "suggest using `dyn Trait` for trait objects",
+        default_level: "warn",
+    },
+    Lint {
+        label: "elided_lifetimes_in_paths",
+        description: "hidden lifetime parameters in types are deprecated",
+        default_level: "allow",
+    },
+    Lint {
+        label: "explicit_outlives_requirements",
+        description: "outlives requirements can be inferred",
+        default_level: "allow",
+    },
+    Lint {
+        label: "trivial_casts",
+        description: "detects casts which do not do anything",
+        default_level: "allow",
+    },
+    Lint {
+        label: "trivial_numeric_casts",
+        description: "detects trivial casts of numeric types which could be removed",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unused_import_braces",
+        description: "unnecessary braces around an imported item",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unused_qualifications",
+        description: "detects unnecessarily qualified names",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unused_lifetimes",
+        description: "detects lifetime parameters that are never used",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unsafe_code",
+        description: "usage of `unsafe` code",
+        default_level: "allow",
+    },
+    Lint {
+        label: "unconditional_recursion",
+        description: "functions that cannot return without calling themselves",
+        default_level: "warn",
+    },
+    Lint {
+        label: "while_true",
+        description: "suggest using `loop { }` instead of `while true { }`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "overflowing_literals",
+        description: "literal out of range for its type",
+        default_level: "deny",
+    },
+    Lint {
+        label: "unused_comparisons",
+        description: "comparisons made useless by limits of the types involved",
+        default_level: "warn",
+    },
+    Lint {
+        label: "path_statements",
+        description: "path statements with no effect",
+        default_level: "warn",
+    },
+    Lint {
+        label: "unused_braces",
+        description: "unnecessary braces around an expression",
+        default_level: "warn",
+    },
+    Lint {
+        label: "ellipsis_inclusive_range_patterns",
+        description: "`...` range patterns are deprecated",
+        default_level: "warn",
+    },
+    Lint {
+        label: "renamed_and_removed_lints",
+        description: "lints that have been renamed or removed",
+        default_level: "warn",
+    },
+    Lint {
+        label: "private_in_public",
+        description: "detect private items in public interfaces not caught by diagnostics",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::all",
+        description: "lints which are rather strongly recommended in general",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::pedantic",
+        description: "lints which are rather strict, and may have false positives",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::nursery",
+        description: "new lints that are still under development",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::cargo",
+        description: "checks for common mistakes in `Cargo.toml` manifests",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::correctness",
+        description: "lints that give you a bug, and are deny by default",
+        default_level: "deny",
+    },
+    Lint {
+        label: "clippy::complexity",
+        description: "lints that find unnecessarily complex code",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::perf",
+        description: "lints that look for improvements in performance",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::style",
+        description: "lints that advise on idiomatic code",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::needless_return",
+        description: "using a return statement like `return expr;` where an expression would suffice",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::needless_lifetimes",
+        description: "checks for lifetime annotations which can be removed because they are inferable",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::needless_range_loop",
+        description: "checks for looping over a range and then indexing a slice with it",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::needless_collect",
+        description: "checks for functions collecting an iterator when collect is not needed",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::needless_pass_by_value",
+        description: "checks for functions taking arguments by value, but not consuming them",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::redundant_clone",
+        description: "checks for a redundant `clone()` (and its relatives) which clones an owned value that is going to be dropped without further use",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::redundant_closure",
+        description: "checks for closures which just call another function where the function could be called directly",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::redundant_field_names",
+        description: "checks for fields in struct literals where the field name and variable name are the same",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::single_match",
+        description: "checks for matches with a single arm where an `if let` will usually suffice",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::single_match_else",
+        description: "checks for matches with two arms where an `if let else` will usually suffice",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::clone_on_copy",
+        description: "checks for usage of `.clone()` on a `Copy` type",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::clone_double_ref",
+        description: "checks for usage of `.clone()` on an `&&T`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::map_clone",
+        description: "checks for usage of `.map(|x| x.clone())`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::manual_map",
+        description: "checks for usages of `match` which could be implemented using `map`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::collapsible_if",
+        description: "checks for nested `if` statements which can be collapsed",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::collapsible_else_if",
+        description: "checks for collapsible `else { if ... }` expressions",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::len_zero",
+        description: "checks for getting the length of something via `.len()` just to compare to zero",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::len_without_is_empty",
+        description: "checks for items that implement `.len()` but not `.is_empty()`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::comparison_chain",
+        description: "checks comparison chains written with `if` that can be rewritten with `match`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::if_same_then_else",
+        description: "checks for `if/else` with the same body",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::match_bool",
+        description: "checks for matches where match expression is a `bool`",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::wildcard_imports",
+        description: "checks for wildcard imports `use _::*`",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::unwrap_used",
+        description: "checks for `.unwrap()` calls on `Option`s and `Result`s",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::expect_used",
+        description: "checks for `.expect()` calls on `Option`s and `Result`s",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::todo",
+        description: "checks for usage of `todo!`",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::unimplemented",
+        description: "checks for usage of `unimplemented!`",
+        default_level: "allow",
+    },
+    Lint {
+        label: "clippy::type_complexity",
+        description: "checks for types used in function signatures that are complex",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::too_many_arguments",
+        description: "checks for functions with too many parameters",
+        default_level: "warn",
+    },
+    Lint {
+        label: "clippy::module_inception",
+        description: "checks for modules that have the same name as their parent module",
+        default_level: "warn",
+    },
+];
+
+/// Returns the lint with the given name, e.g. `"unused_variables"` or
+/// `"clippy::all"`.
+pub fn find_lint(name: &str) -> Option<&'static Lint> {
+    DEFAULT_LINTS.iter().find(|lint| lint.label == name)
+}
+
+/// Suggests the closest known lint name to `partial`, for completion or for
+/// fixing a typo'd `#[allow(...)]`. Ranked purely by edit distance (ties
+/// broken by declaration order); a match further than `max_distance` away
+/// is not a fix for a typo, it's a different lint entirely, so this
+/// returns `None` rather than suggesting it.
+pub fn nearest_lint(partial: &str) -> Option<&'static Lint> {
+    let max_distance = (partial.chars().count() / 3).max(2);
+    DEFAULT_LINTS
+        .iter()
+        .map(|lint| (lint, edit_distance(partial, lint.label)))
+        .min_by_key(|(_, dist)| *dist)
+        .filter(|(_, dist)| *dist <= max_distance)
+        .map(|(lint, _)| lint)
+}
+
+/// Plain Levenshtein distance; the lint table is small enough that this
+/// doesn't need to be fast.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dp[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1]
+            } else {
+                1 + dp[i - 1][j].min(dp[i][j - 1]).min(dp[i - 1][j - 1])
+            };
+        }
+    }
+    dp[a.len()][b.len()]
+}
@@ -0,0 +1,55 @@
+//! Test-only helpers for exercising a single assist handler against a
+//! fixture with a `<|>` cursor marker.
+
+use hir::mock::MockDatabase;
+use ra_db::FileRange;
+use ra_syntax::{TextRange, TextUnit};
+
+use crate::{Assist, AssistCtx};
+
+fn extract_cursor(text: &str) -> (String, TextUnit) {
+    let cursor_pos = text.find("<|>").expect("text should contain a <|> marker");
+    let mut text = text.to_string();
+    text.replace_range(cursor_pos..cursor_pos + "<|>".len(), "");
+    (text, TextUnit::from_usize(cursor_pos))
+}
+
+fn run_assist<F>(assist: F, before: &str, cursor: TextUnit) -> Option<Assist>
+where
+    F: FnOnce(AssistCtx<&MockDatabase>) -> Option<Assist>,
+{
+    let (db, file_id) = MockDatabase::with_single_file(before);
+    let source_file = db.parse(file_id);
+    let frange = FileRange { file_id, range: TextRange::from_to(cursor, cursor) };
+    let ctx = AssistCtx::new(&db, frange, source_file);
+    assist(ctx)
+}
+
+pub(crate) fn check_assist<F>(assist: F, before: &str, after: &str)
+where
+    F: FnOnce(AssistCtx<&MockDatabase>) -> Option<Assist>,
+{
+    let (before, cursor) = extract_cursor(before);
+    let result = run_assist(assist, &before, cursor).expect("assist should be applicable");
+    let mut actual = before;
+    result.source_file_edit.edit.into_text_edit().apply(&mut actual);
+    assert_eq!(actual.trim(), after.replace("<|>", "").trim());
+}
+
+pub(crate) fn check_assist_not_applicable<F>(assist: F, before: &str)
+where
+    F: FnOnce(AssistCtx<&MockDatabase>) -> Option<Assist>,
+{
+    let (before, cursor) = extract_cursor(before);
+    assert!(run_assist(assist, &before, cursor).is_none(), "assist should not be applicable here");
+}
+
+pub(crate) fn check_assist_target<F>(assist: F, before: &str, expected_target: &str)
+where
+    F: FnOnce(AssistCtx<&MockDatabase>) -> Option<Assist>,
+{
+    let (before, cursor) = extract_cursor(before);
+    let result = run_assist(assist, &before, cursor).expect("assist should be applicable");
+    let target = result.source_file_edit.target.expect("assist should set a target range");
+    assert_eq!(&before[target], expected_target);
+}
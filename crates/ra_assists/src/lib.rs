@@ -0,0 +1,103 @@
+//! `ra_assists` crate provides a bunch of code assists, that suggest small
+//! and local code edits, triggered by the cursor position. They are not as
+//! drastic as refactorings, but usually much more relevant, as they are
+//! shown inline and often used just to get rid of an error.
+
+mod assists;
+mod generated {
+    pub(crate) mod lints;
+}
+
+#[cfg(test)]
+mod helpers;
+
+use hir::db::HirDatabase;
+use ra_db::FileRange;
+use ra_syntax::{AstNode, SourceFile, SyntaxKind, SyntaxToken, TextRange, TextUnit};
+use ra_text_edit::TextEditBuilder;
+
+/// Identifies a particular assist, for filtering and testing purposes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AssistId(pub &'static str);
+
+/// A fully-built assist, ready to be shown to the user and applied.
+#[derive(Debug)]
+pub struct Assist {
+    pub id: AssistId,
+    pub label: String,
+    pub source_file_edit: AssistEdit,
+}
+
+#[derive(Debug)]
+pub struct AssistEdit {
+    pub target: Option<TextRange>,
+    pub edit: TextEditBuilder,
+}
+
+/// The context an assist function runs in: the file, the cursor position
+/// (or selection) and a database handle for the (rare) assist that needs
+/// semantic information.
+#[derive(Clone)]
+pub struct AssistCtx<'a, DB> {
+    pub(crate) db: &'a DB,
+    pub(crate) frange: FileRange,
+    pub(crate) source_file: SourceFile,
+}
+
+impl<'a, DB: HirDatabase> AssistCtx<'a, DB> {
+    pub fn new(db: &'a DB, frange: FileRange, source_file: SourceFile) -> AssistCtx<'a, DB> {
+        AssistCtx { db, frange, source_file }
+    }
+
+    /// Finds a token of the given `kind` whose range contains (or directly
+    /// touches) the cursor/selection.
+    pub fn find_token_at_offset(&self, kind: SyntaxKind) -> Option<SyntaxToken> {
+        let offset = self.frange.range.start();
+        self.source_file
+            .syntax()
+            .token_at_offset(offset)
+            .find(|it| it.kind() == kind)
+    }
+
+    /// Builds the assist from the edit and target that `f` records; callers
+    /// invoke this only once their applicability checks have passed, so it
+    /// always returns `Some`.
+    pub fn add_assist(
+        self,
+        id: AssistId,
+        label: impl Into<String>,
+        f: impl FnOnce(&mut AssistBuilder),
+    ) -> Option<Assist> {
+        let mut builder = AssistBuilder::default();
+        f(&mut builder);
+        Some(Assist {
+            id,
+            label: label.into(),
+            source_file_edit: AssistEdit { target: builder.target, edit: builder.edit },
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct AssistBuilder {
+    target: Option<TextRange>,
+    edit: TextEditBuilder,
+}
+
+impl AssistBuilder {
+    /// Marks the range that should be highlighted/scrolled to when this
+    /// assist is previewed.
+    pub fn target(&mut self, range: TextRange) {
+        self.target = Some(range);
+    }
+
+    pub fn replace(&mut self, range: TextRange, replace_with: impl Into<String>) {
+        self.edit.replace(range, replace_with.into());
+    }
+
+    pub fn insert(&mut self, offset: TextUnit, text: impl Into<String>) {
+        self.edit.insert(offset, text.into());
+    }
+}
+
+pub use crate::assists::all_assists;
@@ -0,0 +1,25 @@
+//! The database defines all the queries for computing HIR facts.
+
+use std::sync::Arc;
+
+use ra_db::salsa;
+
+use crate::{
+    generics::{GenericDef, GenericParams},
+    ty::Ty,
+};
+
+#[salsa::query_group(HirDatabaseStorage)]
+pub trait HirDatabase: ra_db::SourceDatabase {
+    #[salsa::invoke(crate::generics::generic_params_query)]
+    fn generic_params(&self, def: GenericDef) -> Arc<GenericParams>;
+
+    /// Lowers an associated-type shorthand path (`T::Item`) to its
+    /// projection type. Guarded against self-referential bounds (e.g. a
+    /// trait bound that, through some chain, requires resolving its own
+    /// shorthand again) via salsa's cycle recovery: a cycle collapses to
+    /// `Ty::Unknown` rather than overflowing the stack.
+    #[salsa::invoke(crate::ty::lower::assoc_type_shorthand_candidate_query)]
+    #[salsa::cycle(crate::ty::lower::recover_assoc_type_shorthand_cycle)]
+    fn assoc_type_shorthand_candidate(&self, def: GenericDef, param_idx: u32, assoc_name: crate::name::Name) -> Ty;
+}
@@ -0,0 +1,157 @@
+//! Resolved generic parameters of functions, structs, impls etc.
+
+use std::sync::Arc;
+
+use crate::{
+    db::HirDatabase, impl_block::ImplBlock, name::Name, resolve::Resolver, type_ref::TypeRef,
+    Function, Struct, Trait, TypeAlias,
+};
+
+/// An unresolved trait bound, e.g. the `Trait<U>` in `T: Trait<U>`. The
+/// trait itself is resolved, but its generic arguments are kept as raw
+/// `TypeRef`s rather than lowered to `Ty` eagerly -- lowering them can
+/// itself require resolving an associated-type shorthand on another
+/// parameter (`T: Trait<U::Item>`), which has to go back through
+/// `db.assoc_type_shorthand_candidate` and therefore through salsa's
+/// cycle detection. See `ty::lower::lower_trait_bound`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraitBound {
+    pub(crate) trait_: Trait,
+    pub(crate) args: Vec<TypeRef>,
+}
+
+/// A single generic parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericParam {
+    pub(crate) idx: u32,
+    pub(crate) name: Name,
+    /// The trait bounds declared directly on the parameter (`<T: Trait>`)
+    /// or via a `where` clause, merged by the existing generic-param
+    /// lowering.
+    pub(crate) bounds: Vec<TraitBound>,
+}
+
+impl GenericParam {
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    pub fn idx(&self) -> u32 {
+        self.idx
+    }
+}
+
+/// The generic parameters of an item, together with those of its parent
+/// (e.g. a method's parent is its impl / trait).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenericParams {
+    pub(crate) parent_params: Option<Arc<GenericParams>>,
+    pub(crate) params: Vec<GenericParam>,
+}
+
+impl GenericParams {
+    pub fn find_by_name(&self, name: &Name) -> Option<&GenericParam> {
+        self.params
+            .iter()
+            .find(|p| &p.name == name)
+            .or_else(|| self.parent_params.as_ref()?.find_by_name(name))
+    }
+
+    pub fn count_parent_params(&self) -> usize {
+        self.parent_params.as_ref().map(|p| p.count_params_including_parent()).unwrap_or(0)
+    }
+
+    pub fn count_params_including_parent(&self) -> usize {
+        self.count_parent_params() + self.params.len()
+    }
+}
+
+/// An item that can have generic parameters: a function, struct, trait, etc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GenericDef {
+    Function(Function),
+    Struct(Struct),
+    Trait(Trait),
+    TypeAlias(TypeAlias),
+    ImplBlock(ImplBlock),
+}
+
+impl_froms!(GenericDef: Function, Struct, Trait, TypeAlias, ImplBlock);
+
+pub trait HasGenericParams {
+    fn generic_params(self, db: &impl HirDatabase) -> Arc<GenericParams>;
+}
+
+impl HasGenericParams for GenericDef {
+    fn generic_params(self, db: &impl HirDatabase) -> Arc<GenericParams> {
+        db.generic_params(self)
+    }
+}
+
+impl GenericDef {
+    /// A resolver whose innermost scope is `self`'s own generic
+    /// parameters, for lowering things (like a bound's generic arguments)
+    /// that are only valid in that scope.
+    pub(crate) fn resolver(self, db: &impl HirDatabase) -> Resolver {
+        Resolver::default().push_generic_params_scope(db, self)
+    }
+}
+
+/// The trait bounds declared on `param`. See the doc comment on
+/// [`TraitBound`] for why these carry unresolved generic arguments.
+pub(crate) fn trait_bounds_for_param(param: &GenericParam) -> &[TraitBound] {
+    &param.bounds
+}
+
+/// The trait bounds for a generic parameter of `def` (or of one of its
+/// parents), found by index. `param_idx` may be
+/// [`crate::resolve::SELF_PARAM_IDX`], in which case the parameter isn't
+/// one of `def`'s real generic parameters at all, and the bounds are those
+/// of `self_type_trait_bounds` instead.
+pub(crate) fn bounds_for_param_idx(
+    db: &impl HirDatabase,
+    def: GenericDef,
+    param_idx: u32,
+) -> Vec<TraitBound> {
+    if param_idx == crate::resolve::SELF_PARAM_IDX {
+        return self_type_trait_bounds(db, def);
+    }
+    let mut scope = Some(db.generic_params(def));
+    while let Some(params) = scope {
+        if let Some(p) = params.params.iter().find(|p| p.idx == param_idx) {
+            return p.bounds.clone();
+        }
+        scope = params.parent_params.clone();
+    }
+    Vec::new()
+}
+
+/// The trait bounds that hold for `def`'s own implicit `Self` type: for a
+/// trait, its supertraits (`trait Sub: Super` means `Self: Super` inside
+/// `Sub`). An impl block, like every other item, gets no bounds here, so
+/// `Self::Output` inside an impl is not resolved through the trait it
+/// implements.
+pub(crate) fn self_type_trait_bounds(db: &impl HirDatabase, def: GenericDef) -> Vec<TraitBound> {
+    match def {
+        GenericDef::Trait(t) => t.source(db).1.lower_supertrait_bounds(db),
+        // We don't currently track the raw (unresolved) trait reference an
+        // impl implements, only the fully lowered one, so `Self::Output`
+        // inside an impl body can't be resolved lazily the same way.
+        GenericDef::ImplBlock(_) => Vec::new(),
+        GenericDef::Function(_) | GenericDef::Struct(_) | GenericDef::TypeAlias(_) => Vec::new(),
+    }
+}
+
+pub(crate) fn generic_params_query(
+    db: &impl HirDatabase,
+    def: GenericDef,
+) -> Arc<GenericParams> {
+    let (params, parent_params) = match def {
+        GenericDef::Function(f) => (f.source(db).1.lower_generic_params(db), f.parent_params(db)),
+        GenericDef::Struct(s) => (s.source(db).1.lower_generic_params(db), None),
+        GenericDef::Trait(t) => (t.source(db).1.lower_generic_params(db), None),
+        GenericDef::TypeAlias(a) => (a.source(db).1.lower_generic_params(db), None),
+        GenericDef::ImplBlock(i) => (i.source(db).1.lower_generic_params(db), None),
+    };
+    Arc::new(GenericParams { parent_params, params })
+}
@@ -0,0 +1,96 @@
+//! Name resolution: turning a `Path` into whatever it refers to (a module,
+//! item, local, generic parameter, ...).
+
+use std::sync::Arc;
+
+use crate::{
+    db::HirDatabase,
+    generics::{self, GenericDef, GenericParam, GenericParams, TraitBound},
+    name::Name,
+    Trait,
+};
+
+/// The index a synthetic `Self` generic parameter is resolved to: `Self`
+/// isn't one of the entries `db.generic_params` returns for a trait or
+/// impl, so it can't share their index space. Callers that look a param
+/// back up by index (`assoc_type_shorthand_candidate_query`) check for
+/// this sentinel explicitly instead of searching `GenericParams`.
+pub(crate) const SELF_PARAM_IDX: u32 = u32::MAX;
+
+/// What a name resolved to in the type namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeDef {
+    PerNs(crate::PerNs),
+    GenericParam(u32),
+    Local,
+}
+
+#[derive(Debug, Clone)]
+struct GenericParamsScope {
+    def: GenericDef,
+    params: Arc<GenericParams>,
+}
+
+/// Resolves names visible at some point in the source, innermost scope
+/// first.
+#[derive(Debug, Clone, Default)]
+pub struct Resolver {
+    scopes: Vec<GenericParamsScope>,
+}
+
+impl Resolver {
+    pub(crate) fn push_generic_params_scope(
+        mut self,
+        db: &impl HirDatabase,
+        def: GenericDef,
+    ) -> Resolver {
+        let params = db.generic_params(def);
+        self.scopes.push(GenericParamsScope { def, params });
+        self
+    }
+
+    /// Resolves a single-segment path to a generic type parameter visible
+    /// in this scope, returning the item in whose scope it was found (its
+    /// bounds are looked up through that item's generic params, parents
+    /// included) together with the parameter itself.
+    ///
+    /// `Self` is handled separately from `GenericParams::find_by_name`:
+    /// it's the implicit type parameter of the innermost enclosing trait
+    /// or impl, and is never one of the entries `db.generic_params`
+    /// returns for that item.
+    pub(crate) fn resolve_generic_param(
+        &self,
+        db: &impl HirDatabase,
+        name: &Name,
+    ) -> Option<(GenericDef, GenericParam)> {
+        if name.to_string() == "Self" {
+            let def = self
+                .scopes
+                .iter()
+                .rev()
+                .map(|scope| scope.def)
+                .find(|def| matches!(def, GenericDef::Trait(_) | GenericDef::ImplBlock(_)))?;
+            let bounds = generics::self_type_trait_bounds(db, def);
+            return Some((def, GenericParam { idx: SELF_PARAM_IDX, name: name.clone(), bounds }));
+        }
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.params.find_by_name(name).map(|p| (scope.def, p.clone())))
+    }
+}
+
+/// The supertraits declared directly on `t` (`trait Sub: Super`), one level
+/// deep, not transitively closed. `ty::lower::trait_and_supertraits` walks
+/// this repeatedly (BFS) to compute the full transitive set when it needs
+/// it, so a 3-level hierarchy like `trait A: B`, `trait B: C` still resolves
+/// an associated type declared on `C` through a bound on `A`.
+///
+/// A supertrait bound is exactly a bound on `t`'s own implicit `Self`
+/// type, so this is just `self_type_trait_bounds` with the args dropped.
+pub(crate) fn trait_supertraits(db: &impl HirDatabase, t: Trait) -> Vec<Trait> {
+    generics::self_type_trait_bounds(db, GenericDef::Trait(t))
+        .iter()
+        .map(|bound: &TraitBound| bound.trait_)
+        .collect()
+}
@@ -0,0 +1,106 @@
+//! The type system. `Ty` is the central type, to which source-level types
+//! (`TypeRef`) get lowered.
+
+use std::sync::Arc;
+
+use crate::{db::HirDatabase, AssocItem, Function, Struct, Trait, TypeAlias};
+
+pub mod display;
+pub(crate) mod lower;
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Substs(pub Arc<[Ty]>);
+
+impl Substs {
+    pub fn empty() -> Substs {
+        Substs(Arc::new([]))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum TypeCtor {
+    Bool,
+    Char,
+    Int,
+    Str,
+    Struct(Struct),
+    FnDef(CallableDef),
+    Tuple { cardinality: u16 },
+    Never,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CallableDef {
+    Function(Function),
+}
+
+impl_froms!(CallableDef: Function);
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ApplicationTy {
+    pub ctor: TypeCtor,
+    pub parameters: Substs,
+}
+
+/// A trait reference, e.g. `T: Iterator` or `T: Into<U>`.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct TraitRef {
+    pub trait_: Trait,
+    pub substs: Substs,
+}
+
+impl Trait {
+    /// The associated type declared directly on this trait with the given
+    /// name, if any (does not look at supertraits).
+    pub(crate) fn associated_type_by_name(
+        self,
+        db: &impl HirDatabase,
+        name: &crate::name::Name,
+    ) -> Option<TypeAlias> {
+        self.items(db).into_iter().find_map(|item| match item {
+            AssocItem::TypeAlias(alias) if alias.name(db) == *name => Some(alias),
+            _ => None,
+        })
+    }
+}
+
+/// An associated-type projection, e.g. the `<T as Iterator>::Item` that
+/// `T::Item` lowers to.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ProjectionTy {
+    pub trait_ref: TraitRef,
+    pub associated_ty_name: crate::name::Name,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Ty {
+    Apply(ApplicationTy),
+    Projection(ProjectionTy),
+    /// A reference to a generic parameter, by its index counting from the
+    /// outermost enclosing item.
+    Param { idx: u32, name: crate::name::Name },
+    Unknown,
+}
+
+pub trait TypeWalk {
+    fn walk(&self, f: &mut impl FnMut(&Ty));
+}
+
+impl TypeWalk for Ty {
+    fn walk(&self, f: &mut impl FnMut(&Ty)) {
+        match self {
+            Ty::Apply(a_ty) => {
+                for t in a_ty.parameters.0.iter() {
+                    t.walk(f);
+                }
+            }
+            Ty::Projection(proj) => {
+                for t in proj.trait_ref.substs.0.iter() {
+                    t.walk(f);
+                }
+            }
+            Ty::Param { .. } | Ty::Unknown => {}
+        }
+        f(self);
+    }
+}
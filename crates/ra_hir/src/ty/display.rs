@@ -0,0 +1,30 @@
+//! Rendering of `Ty` back into a human-readable form, e.g. for hover and
+//! inlay hints.
+
+use std::fmt;
+
+use crate::ty::{ApplicationTy, Ty, TypeCtor};
+
+pub trait HirDisplay {
+    fn hir_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl HirDisplay for Ty {
+    fn hir_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Apply(ApplicationTy { ctor, .. }) => match ctor {
+                TypeCtor::Bool => write!(f, "bool"),
+                TypeCtor::Char => write!(f, "char"),
+                TypeCtor::Int => write!(f, "{{integer}}"),
+                TypeCtor::Str => write!(f, "str"),
+                TypeCtor::Struct(_) => write!(f, "{{struct}}"),
+                TypeCtor::FnDef(_) => write!(f, "fn(..)"),
+                TypeCtor::Tuple { cardinality } => write!(f, "({}...)", "_, ".repeat(*cardinality as usize)),
+                TypeCtor::Never => write!(f, "!"),
+            },
+            Ty::Projection(proj) => write!(f, "<.. as ..>::{}", proj.associated_ty_name),
+            Ty::Param { name, .. } => write!(f, "{}", name),
+            Ty::Unknown => write!(f, "{{unknown}}"),
+        }
+    }
+}
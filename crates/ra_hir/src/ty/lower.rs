@@ -0,0 +1,143 @@
+//! Lowering of source-level types (`TypeRef`, `Path`) to `Ty`.
+//!
+//! The interesting case handled here is the associated-type shorthand,
+//! e.g. `T::Item` or `Self::Output`: when the first segment of a path
+//! resolves to a generic type parameter, the rest of the path has to be
+//! resolved against that parameter's trait bounds rather than as a normal
+//! item path.
+
+use crate::{
+    db::HirDatabase,
+    generics::{bounds_for_param_idx, GenericDef, TraitBound},
+    name::Name,
+    resolve::Resolver,
+    type_ref::TypeRef,
+    ty::{ProjectionTy, Substs, Ty, TraitRef},
+    Path,
+};
+
+/// Lowers a `Path` occurring in type position to a `Ty`.
+pub(crate) fn lower_path(db: &impl HirDatabase, resolver: &Resolver, path: &Path) -> Ty {
+    if let Some(ty) = lower_assoc_type_shorthand(db, resolver, path) {
+        return ty;
+    }
+    // Any other path (struct, type alias, ...) is not resolved here and
+    // lowers to `Ty::Unknown`.
+    Ty::Unknown
+}
+
+/// Lowers a source-level type to a `Ty`, resolving an associated-type
+/// shorthand path against `resolver`'s scope. A reference lowers to the
+/// type it refers to; tuples, `!` and error types lower to `Ty::Unknown`
+/// without looking inside.
+pub(crate) fn lower_type_ref(db: &impl HirDatabase, resolver: &Resolver, type_ref: &TypeRef) -> Ty {
+    match type_ref {
+        TypeRef::Path(path) => lower_path(db, resolver, path),
+        TypeRef::Reference(inner, _) => lower_type_ref(db, resolver, inner),
+        TypeRef::Tuple(_) | TypeRef::Never | TypeRef::Error => Ty::Unknown,
+    }
+}
+
+/// Lowers a path whose first segment is a generic type parameter, e.g.
+/// `T::Item`, to the matching associated-type projection.
+///
+/// Returns `None` if `path` isn't of this shape (not exactly two segments,
+/// or a first segment that doesn't resolve to a generic parameter) so the
+/// caller can fall back to normal path resolution.
+pub(crate) fn lower_assoc_type_shorthand(
+    db: &impl HirDatabase,
+    resolver: &Resolver,
+    path: &Path,
+) -> Option<Ty> {
+    let mut segments = path.segments().iter();
+    let first = segments.next()?;
+    let second = segments.next()?;
+    if segments.next().is_some() {
+        // only the direct `T::Item` shorthand is handled here; longer
+        // paths go through normal item resolution instead
+        return None;
+    }
+    let param_name = first.name.clone();
+    let assoc_name = second.name.clone();
+
+    let (def, param) = resolver.resolve_generic_param(db, &param_name)?;
+    Some(db.assoc_type_shorthand_candidate(def, param.idx(), assoc_name))
+}
+
+/// The actual candidate search, split out into its own (cycle-guarded)
+/// query: resolving `T`'s bounds can itself require lowering a shorthand
+/// that mentions `T` again (directly, through a supertrait, or through
+/// another parameter's bound, e.g. `T: Trait<U::Item>`), so this has to be
+/// re-entrant-safe. Each bound's generic arguments are only lowered here,
+/// lazily -- `bounds_for_param_idx` hands back the raw `TraitBound`s
+/// untouched -- which is what makes that re-entrance actually happen
+/// instead of being computed away before this query is ever called.
+pub(crate) fn assoc_type_shorthand_candidate_query(
+    db: &impl HirDatabase,
+    def: GenericDef,
+    param_idx: u32,
+    assoc_name: Name,
+) -> Ty {
+    let bounds = bounds_for_param_idx(db, def, param_idx);
+    let resolver = def.resolver(db);
+
+    let mut candidates = bounds.iter().filter_map(|bound| {
+        let trait_ref = lower_trait_bound(db, &resolver, bound);
+        let has_match = trait_and_supertraits(db, trait_ref.trait_)
+            .into_iter()
+            .any(|t| t.associated_type_by_name(db, &assoc_name).is_some());
+        has_match.then(|| trait_ref)
+    });
+
+    match (candidates.next(), candidates.next()) {
+        // exactly one trait bound declares a matching associated type --
+        // the shorthand is unambiguous
+        (Some(trait_ref), None) => {
+            Ty::Projection(ProjectionTy { trait_ref, associated_ty_name: assoc_name })
+        }
+        _ => Ty::Unknown,
+    }
+}
+
+/// Lowers a raw bound's generic arguments, turning it into the resolved
+/// `TraitRef` used for projection. This is where recursion back into
+/// `assoc_type_shorthand_candidate` can happen, e.g. lowering the `U::Item`
+/// in `T: Trait<U::Item>`.
+fn lower_trait_bound(db: &impl HirDatabase, resolver: &Resolver, bound: &TraitBound) -> TraitRef {
+    let substs = Substs(bound.args.iter().map(|arg| lower_type_ref(db, resolver, arg)).collect());
+    TraitRef { trait_: bound.trait_, substs }
+}
+
+/// `t` together with the full transitive closure of its supertraits
+/// (`trait A: B`, `trait B: C` means `C` is in scope for a bound on `A`
+/// too), so an associated-type shorthand can be resolved against a bound
+/// several supertrait hops away from where it's declared.
+fn trait_and_supertraits(db: &impl HirDatabase, t: crate::Trait) -> Vec<crate::Trait> {
+    let mut result = vec![t];
+    let mut i = 0;
+    while i < result.len() {
+        for super_trait in crate::resolve::trait_supertraits(db, result[i]) {
+            if !result.contains(&super_trait) {
+                result.push(super_trait);
+            }
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Cycle recovery for `assoc_type_shorthand_candidate`: a genuinely
+/// self-referential shorthand (e.g. `T: Trait<T::Item>`) would otherwise
+/// recurse into itself and overflow the stack. Collapsing the cycle to
+/// `Ty::Unknown` matches how other recursive HIR queries (e.g. type
+/// inference on recursive type aliases) report "can't resolve this"
+/// without panicking.
+pub(crate) fn recover_assoc_type_shorthand_cycle(
+    _db: &impl HirDatabase,
+    _cycle: &[String],
+    _def: &GenericDef,
+    _param_idx: &u32,
+    _assoc_name: &Name,
+) -> Ty {
+    Ty::Unknown
+}
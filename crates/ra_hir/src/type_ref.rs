@@ -0,0 +1,29 @@
+//! `TypeRef` is the syntax-level representation of a type; lowering it to a
+//! `Ty` is what the `ty` module does.
+
+use crate::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Mutability {
+    Shared,
+    Mut,
+}
+
+impl Mutability {
+    pub fn as_keyword_for_ref(self) -> &'static str {
+        match self {
+            Mutability::Shared => "",
+            Mutability::Mut => "mut ",
+        }
+    }
+}
+
+/// A syntax-level representation of a type, before name resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Path(Path),
+    Reference(Box<TypeRef>, Mutability),
+    Tuple(Vec<TypeRef>),
+    Never,
+    Error,
+}